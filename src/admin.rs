@@ -0,0 +1,118 @@
+use std::net::IpAddr;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tokio::{
+    io::{split, AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::TcpStream,
+    sync::{mpsc, oneshot},
+};
+use uuid::Uuid;
+
+/// A target for a `Ban` command: either a specific client id, or every peer
+/// connecting from a given IP.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum BanTarget {
+    Uuid(Uuid),
+    Ip(IpAddr),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AdminCommand {
+    ListPlayers,
+    Kick { uuid: Uuid },
+    Ban { target: BanTarget },
+    SendShine { uuid: Uuid, id: i32 },
+    Crash { uuid: Uuid },
+    Teleport { uuid: Uuid, stage: String, scenario: i8 },
+    StartTag { minutes: u16, seekers: Vec<Uuid> },
+    StopTag,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerSummary {
+    pub id: Uuid,
+    pub name: String,
+    pub connected: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AdminResponse {
+    Ok,
+    Players(Vec<PlayerSummary>),
+    Error(String),
+}
+
+/// A command pulled off the wire by an admin connection, paired with a
+/// `oneshot` the dispatcher uses to route its result back to that connection.
+pub struct AdminRequest {
+    pub command: AdminCommand,
+    pub reply: oneshot::Sender<AdminResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RequestEnvelope {
+    id: u64,
+    command: AdminCommand,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ResponseEnvelope {
+    id: u64,
+    response: AdminResponse,
+}
+
+/// Reads newline-delimited JSON commands from a single admin connection,
+/// submits each to the command bus, and writes back the matching response.
+pub async fn handle_admin_connection(socket: TcpStream, bus: mpsc::Sender<AdminRequest>) -> Result<()> {
+    let (reader, mut writer) = split(socket);
+    let mut lines = BufReader::new(reader).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let envelope: RequestEnvelope = match serde_json::from_str(&line) {
+            Ok(envelope) => envelope,
+            Err(e) => {
+                let response = ResponseEnvelope {
+                    id: 0,
+                    response: AdminResponse::Error(format!("Invalid request: {}", e)),
+                };
+                write_response(&mut writer, &response).await?;
+                continue;
+            }
+        };
+
+        let (reply_tx, reply_rx) = oneshot::channel();
+        bus.send(AdminRequest {
+            command: envelope.command,
+            reply: reply_tx,
+        })
+        .await?;
+
+        let response = reply_rx.await?;
+        write_response(
+            &mut writer,
+            &ResponseEnvelope {
+                id: envelope.id,
+                response,
+            },
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+async fn write_response(
+    writer: &mut (impl AsyncWriteExt + Unpin),
+    response: &ResponseEnvelope,
+) -> Result<()> {
+    let mut line = serde_json::to_vec(response)?;
+    line.push(b'\n');
+    writer.write_all(&line).await?;
+
+    Ok(())
+}
@@ -0,0 +1,12 @@
+pub mod admin;
+pub mod metrics;
+pub mod packet;
+pub mod peer;
+pub mod players;
+pub mod server;
+pub mod settings;
+pub mod storage;
+pub mod tag;
+
+pub use server::Server;
+pub use settings::Settings;
@@ -0,0 +1,42 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use anyhow::Result;
+use smo_online_server::{server::Server, settings::Settings};
+use tokio::net::TcpListener;
+use tracing::{error, info, warn};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let settings = Settings::from_env();
+    let admin_port = settings.admin_port;
+    let server = Arc::new(Server::new(settings));
+
+    if let Some(port) = admin_port {
+        let server = server.clone();
+        let addr = SocketAddr::from(([0, 0, 0, 0], port));
+
+        tokio::spawn(async move {
+            if let Err(e) = server.listen_admin(addr).await {
+                error!("Admin listener stopped: {}", e);
+            }
+        });
+    }
+
+    let listener = TcpListener::bind("0.0.0.0:1027").await?;
+    info!("Listening on {}", listener.local_addr()?);
+
+    loop {
+        let (socket, addr) = listener.accept().await?;
+        let server = server.clone();
+
+        tokio::spawn(async move {
+            info!("New connection from {}", addr);
+
+            if let Err(e) = server.handle_connection(socket).await {
+                warn!("Connection from {} closed: {}", addr, e);
+            }
+        });
+    }
+}
@@ -0,0 +1,120 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use anyhow::Result;
+use prometheus::{Encoder, IntCounterVec, IntGauge, Opts, Registry, TextEncoder};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+use tracing::info;
+
+/// Counters and gauges describing a running `Server`, scraped over the small
+/// HTTP listener started by [`serve`].
+pub struct Metrics {
+    registry: Registry,
+    pub peers_connected: IntGauge,
+    pub players_known: IntGauge,
+    pub packets_total: IntCounterVec,
+    pub rejected_joins_total: IntCounterVec,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let peers_connected =
+            IntGauge::new("smo_peers_connected", "Currently connected peers").unwrap();
+        let players_known =
+            IntGauge::new("smo_players_known", "Players known to the server, connected or not")
+                .unwrap();
+
+        let packets_total = IntCounterVec::new(
+            Opts::new("smo_packets_total", "Packets handled, by content type"),
+            &["content"],
+        )
+        .unwrap();
+
+        let rejected_joins_total = IntCounterVec::new(
+            Opts::new("smo_rejected_joins_total", "Joins rejected, by reason"),
+            &["reason"],
+        )
+        .unwrap();
+
+        registry
+            .register(Box::new(peers_connected.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(players_known.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(packets_total.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(rejected_joins_total.clone()))
+            .unwrap();
+
+        Self {
+            registry,
+            peers_connected,
+            players_known,
+            packets_total,
+            rejected_joins_total,
+        }
+    }
+
+    fn render(&self) -> Vec<u8> {
+        let encoder = TextEncoder::new();
+        let mut buffer = vec![];
+        encoder
+            .encode(&self.registry.gather(), &mut buffer)
+            .expect("metrics encode to an in-memory buffer cannot fail");
+
+        buffer
+    }
+}
+
+/// Serves `metrics` as plaintext Prometheus exposition format to any connection
+/// on `addr`. Intended to be scraped, not browsed, so we skip parsing the
+/// request beyond draining it.
+pub async fn serve(addr: SocketAddr, metrics: Arc<Metrics>) -> Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    info!("Serving metrics on {}", addr);
+
+    loop {
+        let (mut socket, _) = listener.accept().await?;
+        let metrics = metrics.clone();
+
+        tokio::spawn(async move {
+            let mut buf = [0; 1024];
+            let _ = socket.read(&mut buf).await;
+
+            let body = metrics.render();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\n\r\n",
+                body.len()
+            );
+
+            if socket.write_all(response.as_bytes()).await.is_ok() {
+                let _ = socket.write_all(&body).await;
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_reflects_counter_and_gauge_updates() {
+        let metrics = Metrics::new();
+
+        metrics.peers_connected.set(3);
+        metrics.packets_total.with_label_values(&["keepalive"]).inc();
+
+        let output = String::from_utf8(metrics.render()).unwrap();
+
+        assert!(output.contains("smo_peers_connected 3"));
+        assert!(output.contains("smo_packets_total"));
+    }
+}
@@ -0,0 +1,90 @@
+use anyhow::{anyhow, Result};
+use bytes::Bytes;
+use uuid::Uuid;
+
+pub const HEADER_SIZE: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionType {
+    First,
+    Reconnect,
+}
+
+#[derive(Debug, Clone)]
+pub enum Content {
+    Init {
+        max_player: i16,
+    },
+    Connect {
+        type_: ConnectionType,
+        max_player: u16,
+        client: String,
+    },
+    Disconnect,
+    Costume {
+        body: String,
+        cap: String,
+    },
+    Game {
+        is_2d: bool,
+        scenario: i8,
+        stage: String,
+    },
+    Tag {
+        update_type: u8,
+        is_it: bool,
+        seconds: u8,
+        minutes: u16,
+    },
+    Shine {
+        id: i32,
+    },
+    Keepalive,
+}
+
+impl Content {
+    pub fn is_connect(&self) -> bool {
+        matches!(self, Content::Connect { .. })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Packet {
+    pub id: Uuid,
+    pub content: Content,
+}
+
+impl Packet {
+    pub fn new(id: Uuid, content: Content) -> Self {
+        Self { id, content }
+    }
+
+    pub fn to_bytes(&self) -> Bytes {
+        // TODO: encode according to the wire format used by the game client
+        Bytes::new()
+    }
+}
+
+pub struct Header {
+    pub id: Uuid,
+    pub packet_size: usize,
+}
+
+impl Header {
+    pub fn from_bytes(bytes: Bytes) -> Result<Self> {
+        if bytes.len() != HEADER_SIZE {
+            return Err(anyhow!("Header is not {} bytes long", HEADER_SIZE));
+        }
+
+        // TODO: decode according to the wire format used by the game client
+        Ok(Self {
+            id: Uuid::nil(),
+            packet_size: 0,
+        })
+    }
+
+    pub fn make_packet(&self, _body: Bytes) -> Result<Packet> {
+        // TODO: decode body according to the wire format used by the game client
+        Ok(Packet::new(self.id, Content::Disconnect))
+    }
+}
@@ -0,0 +1,89 @@
+use std::{collections::HashMap, net::SocketAddr, sync::Arc};
+
+use tokio::{
+    io::{AsyncWriteExt, WriteHalf},
+    net::TcpStream,
+    sync::{mpsc, RwLock},
+};
+use tracing::debug;
+use uuid::Uuid;
+
+use super::packet::Packet;
+
+// Outbound packets queued per peer before we consider the client too far behind
+// and disconnect it, rather than letting it stall delivery to everyone else.
+const CHANNEL_BUFFER: usize = 64;
+
+pub struct Peer {
+    pub id: Uuid,
+    pub ip: SocketAddr,
+    pub connected: bool,
+    sender: Option<mpsc::Sender<Packet>>,
+}
+
+impl Peer {
+    pub fn new(ip: SocketAddr, writer: WriteHalf<TcpStream>) -> Self {
+        let id = Uuid::new_v4();
+        let (sender, receiver) = mpsc::channel(CHANNEL_BUFFER);
+
+        tokio::spawn(write_loop(id, writer, receiver));
+
+        Self {
+            id,
+            ip,
+            connected: true,
+            sender: Some(sender),
+        }
+    }
+
+    /// Queues `packet` for the writer task to send. Returns `false` if the peer's
+    /// outbound queue is already full, meaning the client has fallen too far behind.
+    pub fn try_send(&self, packet: Packet) -> bool {
+        match &self.sender {
+            Some(sender) => sender.try_send(packet).is_ok(),
+            None => false,
+        }
+    }
+
+    pub async fn send(&self, packet: Packet) {
+        if let Some(sender) = &self.sender {
+            let _ = sender.send(packet).await;
+        }
+    }
+
+    pub async fn disconnect(&mut self) {
+        // Dropping the sender closes the channel, which ends the writer task's
+        // loop and shuts down the underlying socket half.
+        self.sender.take();
+    }
+}
+
+/// Flags `ids` as disconnected in `peers` so the reaper task evicts them instead
+/// of broadcasts blocking on them. Shared by `Server::disconnect_slow_peers` and
+/// `tag::broadcast_time`, both of which fall back to this once a peer's outbound
+/// queue fills up.
+pub(crate) async fn disconnect_slow_peers(peers: &Arc<RwLock<HashMap<Uuid, Peer>>>, ids: Vec<Uuid>) {
+    if ids.is_empty() {
+        return;
+    }
+
+    let mut peers = peers.write().await;
+
+    for id in ids {
+        if let Some(peer) = peers.get_mut(&id) {
+            debug!("Peer {} fell too far behind, disconnecting", id);
+            peer.connected = false;
+        }
+    }
+}
+
+async fn write_loop(id: Uuid, mut writer: WriteHalf<TcpStream>, mut receiver: mpsc::Receiver<Packet>) {
+    while let Some(packet) = receiver.recv().await {
+        if let Err(e) = writer.write_all(&packet.to_bytes()).await {
+            debug!("Error sending packet to peer {}: {}", id, e);
+            break;
+        }
+    }
+
+    let _ = writer.shutdown().await;
+}
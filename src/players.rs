@@ -0,0 +1,149 @@
+use std::{collections::HashMap, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use super::packet::Packet;
+use crate::storage::{PersistedPlayer, Storage};
+
+pub type SharedPlayer = Arc<RwLock<Player>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Costume {
+    pub body: String,
+    pub cap: String,
+}
+
+pub struct Player {
+    pub id: Uuid,
+    pub name: String,
+    pub costume: Option<Costume>,
+    pub scenario: Option<i8>,
+    pub is_2d: bool,
+    pub is_speedrun: bool,
+    pub shine_sync: Vec<i32>,
+    pub last_game_packet: Option<Packet>,
+}
+
+impl Player {
+    pub fn new(id: Uuid, name: String) -> Self {
+        Self {
+            id,
+            name,
+            costume: None,
+            scenario: None,
+            is_2d: false,
+            is_speedrun: false,
+            shine_sync: vec![],
+            last_game_packet: None,
+        }
+    }
+
+    pub fn set_costume(&mut self, body: String, cap: String) {
+        self.costume = Some(Costume { body, cap });
+    }
+
+    pub async fn persist_shines(&self, storage: &Storage) {
+        let persisted = PersistedPlayer {
+            name: self.name.clone(),
+            costume: self.costume.clone(),
+            shines: self.shine_sync.clone(),
+        };
+
+        if let Err(e) = storage.save_player(&self.id, &persisted).await {
+            warn!("Failed to persist player {}: {}", self.id, e);
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Players {
+    players: Arc<RwLock<HashMap<Uuid, SharedPlayer>>>,
+    storage: Arc<Storage>,
+}
+
+impl Players {
+    pub fn new(storage: Arc<Storage>) -> Self {
+        Self {
+            players: Arc::default(),
+            storage,
+        }
+    }
+
+    /// Inserts `player`, restoring their persisted shine bag and costume (if any)
+    /// so a join after a server restart doesn't lose prior progress.
+    pub async fn add(&self, mut player: Player) -> SharedPlayer {
+        match self.storage.load_player(&player.id).await {
+            Ok(Some(persisted)) => {
+                player.shine_sync = persisted.shines;
+                player.costume = persisted.costume;
+            }
+            Ok(None) => (),
+            Err(e) => warn!("Failed to load persisted player {}: {}", player.id, e),
+        }
+
+        let id = player.id;
+        let shared = Arc::new(RwLock::new(player));
+
+        self.players.write().await.insert(id, shared.clone());
+
+        shared
+    }
+
+    pub async fn get(&self, id: &Uuid) -> Option<SharedPlayer> {
+        self.players.read().await.get(id).cloned()
+    }
+
+    /// Merges any persisted shines into an already-connected player's in-memory
+    /// shine set, in case they were saved by a prior session for this same id.
+    pub async fn merge_persisted_shines(&self, id: &Uuid) {
+        let Some(player) = self.get(id).await else {
+            return;
+        };
+
+        let persisted = match self.storage.load_player(id).await {
+            Ok(Some(persisted)) => persisted,
+            Ok(None) => return,
+            Err(e) => {
+                warn!("Failed to load persisted player {}: {}", id, e);
+                return;
+            }
+        };
+
+        let mut player = player.write().await;
+        for shine in persisted.shines {
+            if !player.shine_sync.contains(&shine) {
+                player.shine_sync.push(shine);
+            }
+        }
+    }
+
+    pub async fn remove(&self, id: &Uuid) -> Option<SharedPlayer> {
+        let removed = self.players.write().await.remove(id);
+
+        if let Some(player) = &removed {
+            let player = player.read().await;
+            player.persist_shines(&self.storage).await;
+            debug!("Persisted and removed player {}", player.id);
+        }
+
+        removed
+    }
+
+    pub async fn get_last_game_packets(&self) -> Vec<Packet> {
+        let players = self.players.read().await;
+        let mut packets = Vec::with_capacity(players.len());
+
+        for player in players.values() {
+            let player = player.read().await;
+
+            if let Some(packet) = &player.last_game_packet {
+                packets.push(packet.clone());
+            }
+        }
+
+        packets
+    }
+}
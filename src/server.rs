@@ -1,7 +1,11 @@
 use crate::{
-    peer::Peer,
+    admin::{handle_admin_connection, AdminCommand, AdminRequest, AdminResponse, BanTarget, PlayerSummary},
+    metrics::{self, Metrics},
+    peer::{self, Peer},
     players::{Players, SharedPlayer},
-    settings::Settings,
+    settings::{BanList, Settings},
+    storage::Storage,
+    tag::{self, TagState},
 };
 
 use super::{
@@ -12,42 +16,100 @@ use anyhow::anyhow;
 use anyhow::Result;
 use bytes::Bytes;
 use futures::{future::join_all, Future};
-use std::collections::HashMap;
+use std::{collections::HashMap, net::SocketAddr, sync::Arc, time::Duration};
 use tokio::{
     io::{split, AsyncReadExt, ReadHalf},
-    net::TcpStream,
-    sync::RwLock,
+    net::{TcpListener, TcpStream},
+    sync::{mpsc, Mutex, RwLock},
+    task::JoinHandle,
+    time::timeout,
 };
-use tracing::{debug, info};
+use tracing::{debug, error, info};
 use uuid::Uuid;
 
 const MAX_PLAYER: i16 = 10;
 
+// How long we'll wait for a packet before nudging the client with a keepalive.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(15);
+// How often the reaper checks for peers that went stale.
+const REAP_INTERVAL: Duration = Duration::from_secs(15);
+// How long to wait after a speedrun re-enables moon sync before resending the
+// client's shine bag, mirroring the original server's `ClientSyncShineBag` delay.
+const MOON_SYNC_DELAY: Duration = Duration::from_secs(15);
+
 pub struct Server {
-    peers: RwLock<HashMap<Uuid, Peer>>,
+    peers: Arc<RwLock<HashMap<Uuid, Peer>>>,
     players: Players,
     settings: Settings,
+    metrics: Arc<Metrics>,
+    storage: Arc<Storage>,
+    // The moderation-facing, runtime-mutable ban list; `settings.ban_list` is only
+    // the config/persisted seed it was built from at startup.
+    ban_list: RwLock<BanList>,
+    tag: Arc<RwLock<TagState>>,
+    tag_ticker: Mutex<Option<JoinHandle<()>>>,
 }
 
 impl Server {
-    pub fn new(settings: Settings) -> Self {
+    pub fn new(mut settings: Settings) -> Self {
+        let storage =
+            Arc::new(Storage::open(&settings.storage_path).expect("failed to open persistent storage"));
+
+        let persisted_bans = storage.load_ban_list().unwrap_or_else(|e| {
+            error!("Failed to load persisted ban list: {}", e);
+            Default::default()
+        });
+        settings.ban_list.ips.extend(persisted_bans.ips);
+        settings.ban_list.ids.extend(persisted_bans.ids);
+
+        let peers = Arc::<RwLock<HashMap<Uuid, Peer>>>::default();
+        let players = Players::new(storage.clone());
+        let metrics = Arc::new(Metrics::new());
+        let ban_list = RwLock::new(settings.ban_list.clone());
+
+        tokio::spawn(reap_stale_peers(peers.clone(), players.clone(), metrics.clone()));
+
+        if let Some(port) = settings.metrics_port {
+            let addr = SocketAddr::from(([0, 0, 0, 0], port));
+            let metrics = metrics.clone();
+
+            tokio::spawn(async move {
+                if let Err(e) = metrics::serve(addr, metrics).await {
+                    error!("Metrics server stopped: {}", e);
+                }
+            });
+        }
+
         Self {
-            peers: RwLock::default(),
-            players: Players::new(),
+            peers,
+            players,
             settings,
+            metrics,
+            storage,
+            ban_list,
+            tag: Arc::default(),
+            tag_ticker: Mutex::new(None),
         }
     }
 
     async fn broadcast(&self, packet: Packet) {
         let peers = self.peers.read().await;
 
-        join_all(
-            peers
-                .iter()
-                .filter(|(_, p)| p.connected && p.id != packet.id)
-                .map(|(_, p)| p.send(packet.clone())),
-        )
-        .await;
+        let fell_behind: Vec<Uuid> = peers
+            .iter()
+            .filter(|(_, p)| p.connected && p.id != packet.id)
+            .filter_map(|(id, p)| {
+                if p.try_send(packet.clone()) {
+                    None
+                } else {
+                    Some(*id)
+                }
+            })
+            .collect();
+
+        drop(peers);
+
+        self.disconnect_slow_peers(fell_behind).await;
     }
 
     async fn broadcast_map<F, Fut>(&self, packet: Packet, map: F)
@@ -57,20 +119,37 @@ impl Server {
     {
         let peers = self.peers.read().await;
 
-        join_all(
+        let fell_behind: Vec<Uuid> = join_all(
             peers
                 .iter()
                 .filter(|(_, p)| p.connected && p.id != packet.id)
-                .map(|(_, peer)| async {
+                .map(|(id, peer)| async move {
                     let packet = match self.players.get(&packet.id).await {
                         Some(p) => (map)(p, packet.clone()).await,
                         None => packet.clone(),
                     };
 
-                    peer.send(packet).await;
+                    if peer.try_send(packet) {
+                        None
+                    } else {
+                        Some(*id)
+                    }
                 }),
         )
-        .await;
+        .await
+        .into_iter()
+        .flatten()
+        .collect();
+
+        drop(peers);
+
+        self.disconnect_slow_peers(fell_behind).await;
+    }
+
+    /// Flags peers whose outbound queue is full as disconnected so the reaper
+    /// task (see `reap_stale_peers`) evicts them instead of broadcast blocking on them.
+    async fn disconnect_slow_peers(&self, ids: Vec<Uuid>) {
+        peer::disconnect_slow_peers(&self.peers, ids).await;
     }
 
     pub async fn handle_connection(&self, socket: TcpStream) -> Result<()> {
@@ -106,6 +185,7 @@ impl Server {
 
         if connected_peers == MAX_PLAYER {
             info!("Player {} couldn't join server is full", packet.id);
+            self.metrics.rejected_joins_total.with_label_values(&["full"]).inc();
             return Err(anyhow!("Server full"));
         }
 
@@ -113,8 +193,13 @@ impl Server {
 
         let mut peers = self.peers.write().await;
 
-        // Remove stales clients and only keep the disconnected one
-        let _ = peers.remove(&packet.id);
+        // Remove stales clients and only keep the disconnected one. Every entry in
+        // `peers` is counted in the gauge until it is removed, including one the
+        // reaper hasn't swept yet; an entry removed here never goes through
+        // `reap_stale_peers`, so it is uncounted here instead.
+        if peers.remove(&packet.id).is_some() {
+            self.metrics.peers_connected.dec();
+        }
 
         match (packet.content, self.players.get(&packet.id).await) {
             // Player already exist so reconnecting
@@ -123,6 +208,11 @@ impl Server {
 
                 peer.id = packet.id;
                 peers.insert(packet.id, peer);
+
+                self.players.merge_persisted_shines(&packet.id).await;
+
+                self.metrics.peers_connected.inc();
+                self.metrics.packets_total.with_label_values(&["connect"]).inc();
             }
             // Player doesn't exist so we create it
             (
@@ -140,9 +230,20 @@ impl Server {
 
                 let _ = self.players.add(player).await;
 
-                let peer = self.on_new_peer(peer).await?;
+                let peer = match self.on_new_peer(peer).await {
+                    Ok(peer) => peer,
+                    Err(e) => {
+                        self.players.remove(&packet.id).await;
+                        self.metrics.rejected_joins_total.with_label_values(&["banned"]).inc();
+                        return Err(e);
+                    }
+                };
 
                 peers.insert(packet.id, peer);
+
+                self.metrics.peers_connected.inc();
+                self.metrics.players_known.inc();
+                self.metrics.packets_total.with_label_values(&["connect"]).inc();
             }
             _ => {
                 debug!("This case isn't supposed to be reach");
@@ -204,8 +305,37 @@ impl Server {
             .await
             .expect("Player is supposed to be here");
 
+        let mut missed_keepalives = 0u8;
+
         loop {
-            let packet = receive_packet(&mut reader).await?;
+            let packet = match timeout(IDLE_TIMEOUT, receive_packet(&mut reader)).await {
+                Ok(packet) => {
+                    missed_keepalives = 0;
+                    packet?
+                }
+                Err(_) => {
+                    missed_keepalives += 1;
+
+                    if missed_keepalives >= 2 {
+                        debug!("Peer {} went stale, closing connection", id);
+
+                        let mut peers = self.peers.write().await;
+                        if let Some(peer) = peers.get_mut(&id) {
+                            peer.connected = false;
+                        }
+
+                        break;
+                    }
+
+                    let peers = self.peers.read().await;
+                    if let Some(peer) = peers.get(&id) {
+                        peer.send(Packet::new(id, Content::Keepalive)).await;
+                    }
+                    drop(peers);
+
+                    continue;
+                }
+            };
 
             if packet.id != id {
                 debug!("Id mismatch: received {} - expecting {}", packet.id, id);
@@ -219,6 +349,8 @@ impl Server {
 
             match &packet.content {
                 Content::Costume { body, cap } => {
+                    self.metrics.packets_total.with_label_values(&["costume"]).inc();
+
                     let mut player = player.write().await;
 
                     player.set_costume(body.clone(), cap.clone());
@@ -229,6 +361,8 @@ impl Server {
                     scenario,
                     stage,
                 } => {
+                    self.metrics.packets_total.with_label_values(&["game"]).inc();
+
                     let mut player = player.write().await;
 
                     player.scenario = Some(*scenario);
@@ -238,19 +372,15 @@ impl Server {
                     if stage == "CapWorldHomeStage" && *scenario == 0 {
                         player.is_speedrun = true;
                         player.shine_sync = vec![];
-                        player.persist_shines().await;
+                        player.persist_shines(&self.storage).await;
                         info!("Entered Cap on new save, preventing moon sync until Cascade");
                     } else if stage == "WaterfallWorldHomeStage" {
                         let was_speedrun = player.is_speedrun;
                         player.is_speedrun = false;
 
                         if was_speedrun {
-                            // TODO:
-                            // Task.Run(async () => {
-                            //     c.Logger.Info("Entered Cascade with moon sync disabled, enabling moon sync");
-                            //     await Task.Delay(15000);
-                            //     await ClientSyncShineBag(c);
-                            // });
+                            info!("Entered Cascade with moon sync disabled, enabling moon sync for {}", id);
+                            tokio::spawn(sync_shine_bag(self.peers.clone(), id, player.shine_sync.clone()));
                         }
                     }
 
@@ -283,42 +413,63 @@ impl Server {
                 Content::Tag {
                     update_type,
                     is_it,
-                    seconds,
-                    minutes,
-                } => (),
-                Content::Disconnect => break,
+                    seconds: _,
+                    minutes: _,
+                } => {
+                    self.metrics.packets_total.with_label_values(&["tag"]).inc();
+
+                    if self.settings.is_tag_enabled {
+                        self.handle_tag_update(id, *update_type, *is_it).await;
+                    }
+                }
+                Content::Shine { id: shine_id } => {
+                    self.metrics.packets_total.with_label_values(&["shine"]).inc();
+
+                    let mut player = player.write().await;
+
+                    if !player.shine_sync.contains(shine_id) {
+                        player.shine_sync.push(*shine_id);
+                        player.persist_shines(&self.storage).await;
+                    }
+
+                    drop(player);
+                }
+                Content::Disconnect => {
+                    self.metrics.packets_total.with_label_values(&["disconnect"]).inc();
+                    break;
+                }
                 _ => (),
             }
 
             self.broadcast(packet).await;
         }
 
-        // TODO: Find out when peers & players are cleaned
+        // Peers & players are cleaned up by the reaper task spawned in `Server::new`,
+        // which scans for `connected == false` entries; we just mark this one here.
+        // The entry may already be gone if an admin kick/ban (see `admin_disconnect`/
+        // `admin_ban`) flagged it and the reaper evicted it while we were still
+        // blocked reading the socket, so tolerate a miss rather than panicking.
         let mut peers = self.peers.write().await;
-        let mut peer = peers.get_mut(&id).expect("Peer is supposed to be here");
-
-        peer.connected = false;
-        peer.disconnect().await;
+        if let Some(peer) = peers.get_mut(&id) {
+            peer.connected = false;
+            peer.disconnect().await;
+        }
 
         Ok(())
     }
 
     async fn on_new_peer(&self, peer: Peer) -> Result<Peer> {
-        let is_ip_banned = self
-            .settings
-            .ban_list
+        let ban_list = self.ban_list.read().await;
+
+        let is_ip_banned = ban_list
             .ips
             .iter()
-            .find(|addr| **addr == peer.ip)
+            .find(|addr| **addr == peer.ip.ip())
             .is_some();
 
-        let is_id_banned = self
-            .settings
-            .ban_list
-            .ids
-            .iter()
-            .find(|addr| **addr == peer.id)
-            .is_some();
+        let is_id_banned = ban_list.ids.iter().find(|addr| **addr == peer.id).is_some();
+
+        drop(ban_list);
 
         if is_id_banned || is_ip_banned {
             info!(
@@ -341,6 +492,223 @@ impl Server {
             Ok(peer)
         }
     }
+
+    /// Binds an admin control listener on `addr` and runs its command bus until
+    /// the listener errors. Modeled on zed-rpc's `Peer`: each connection tags its
+    /// requests with a message id and gets replies routed back through a
+    /// `oneshot` the dispatcher resolves once it has handled the command.
+    pub async fn listen_admin(self: Arc<Self>, addr: SocketAddr) -> Result<()> {
+        let (tx, mut rx) = mpsc::channel::<AdminRequest>(32);
+
+        let server = self.clone();
+        tokio::spawn(async move {
+            while let Some(request) = rx.recv().await {
+                let response = server.dispatch_admin(request.command).await;
+                let _ = request.reply.send(response);
+            }
+        });
+
+        let listener = TcpListener::bind(addr).await?;
+        info!("Listening for admin connections on {}", addr);
+
+        loop {
+            let (socket, peer_addr) = listener.accept().await?;
+            let tx = tx.clone();
+
+            tokio::spawn(async move {
+                if let Err(e) = handle_admin_connection(socket, tx).await {
+                    debug!("Admin connection from {} closed: {}", peer_addr, e);
+                }
+            });
+        }
+    }
+
+    async fn dispatch_admin(&self, command: AdminCommand) -> AdminResponse {
+        match command {
+            AdminCommand::ListPlayers => self.admin_list_players().await,
+            AdminCommand::Kick { uuid } => self.admin_disconnect(uuid).await,
+            AdminCommand::Ban { target } => self.admin_ban(target).await,
+            AdminCommand::SendShine { uuid, id } => {
+                self.admin_send_to(uuid, Content::Shine { id }).await
+            }
+            // Sending an out-of-range shine id is the same crash vector the game's
+            // own moon-sync code can trigger accidentally; reuse it deliberately here.
+            AdminCommand::Crash { uuid } => self.admin_send_to(uuid, Content::Shine { id: -1 }).await,
+            AdminCommand::Teleport {
+                uuid,
+                stage,
+                scenario,
+            } => {
+                self.admin_send_to(
+                    uuid,
+                    Content::Game {
+                        is_2d: false,
+                        scenario,
+                        stage,
+                    },
+                )
+                .await
+            }
+            AdminCommand::StartTag { minutes, seekers } => self.admin_start_tag(minutes, seekers).await,
+            AdminCommand::StopTag => self.admin_stop_tag().await,
+        }
+    }
+
+    /// Applies an incoming `Content::Tag` state update from `id`'s own client —
+    /// the authoritative source for who has just been caught or is now seeking.
+    async fn handle_tag_update(&self, id: Uuid, update_type: u8, is_it: bool) {
+        if update_type != tag::UPDATE_TYPE_STATE {
+            return;
+        }
+
+        let mut state = self.tag.write().await;
+        if !state.running {
+            return;
+        }
+
+        if is_it {
+            state.seekers.insert(id);
+        } else {
+            state.seekers.remove(&id);
+        }
+    }
+
+    async fn admin_start_tag(&self, minutes: u16, seekers: Vec<Uuid>) -> AdminResponse {
+        if !self.settings.is_tag_enabled {
+            return AdminResponse::Error("Tag mode is disabled".to_string());
+        }
+
+        {
+            let mut state = self.tag.write().await;
+            state.running = true;
+            state.remaining_seconds = minutes as u32 * 60;
+            state.seekers = seekers.iter().copied().collect();
+        }
+
+        let peers = self.peers.read().await;
+        let fell_behind: Vec<Uuid> = peers
+            .iter()
+            .filter(|(_, p)| p.connected)
+            .filter_map(|(id, peer)| {
+                let is_it = seekers.contains(id);
+                let packet = Packet::new(
+                    *id,
+                    Content::Tag {
+                        update_type: tag::UPDATE_TYPE_STATE,
+                        is_it,
+                        seconds: 0,
+                        minutes,
+                    },
+                );
+
+                if peer.try_send(packet) {
+                    None
+                } else {
+                    Some(*id)
+                }
+            })
+            .collect();
+        drop(peers);
+
+        self.disconnect_slow_peers(fell_behind).await;
+
+        let mut ticker = self.tag_ticker.lock().await;
+        if let Some(handle) = ticker.take() {
+            handle.abort();
+        }
+        *ticker = Some(tokio::spawn(tag::run_ticker(self.peers.clone(), self.tag.clone())));
+
+        AdminResponse::Ok
+    }
+
+    async fn admin_stop_tag(&self) -> AdminResponse {
+        self.tag.write().await.running = false;
+
+        let mut ticker = self.tag_ticker.lock().await;
+        if let Some(handle) = ticker.take() {
+            handle.abort();
+        }
+
+        AdminResponse::Ok
+    }
+
+    async fn admin_list_players(&self) -> AdminResponse {
+        let peers = self.peers.read().await;
+        let mut players = Vec::with_capacity(peers.len());
+
+        for (id, peer) in peers.iter() {
+            let name = match self.players.get(id).await {
+                Some(player) => player.read().await.name.clone(),
+                None => continue,
+            };
+
+            players.push(PlayerSummary {
+                id: *id,
+                name,
+                connected: peer.connected,
+            });
+        }
+
+        AdminResponse::Players(players)
+    }
+
+    async fn admin_send_to(&self, uuid: Uuid, content: Content) -> AdminResponse {
+        let peers = self.peers.read().await;
+
+        match peers.get(&uuid) {
+            Some(peer) => {
+                peer.send(Packet::new(uuid, content)).await;
+                AdminResponse::Ok
+            }
+            None => AdminResponse::Error(format!("No connected peer {}", uuid)),
+        }
+    }
+
+    async fn admin_disconnect(&self, uuid: Uuid) -> AdminResponse {
+        let mut peers = self.peers.write().await;
+
+        match peers.get_mut(&uuid) {
+            Some(peer) => {
+                peer.connected = false;
+                peer.disconnect().await;
+                AdminResponse::Ok
+            }
+            None => AdminResponse::Error(format!("No connected peer {}", uuid)),
+        }
+    }
+
+    async fn admin_ban(&self, target: BanTarget) -> AdminResponse {
+        {
+            let mut ban_list = self.ban_list.write().await;
+            match &target {
+                BanTarget::Uuid(uuid) => ban_list.ids.push(*uuid),
+                BanTarget::Ip(ip) => ban_list.ips.push(*ip),
+            }
+
+            if let Err(e) = self.storage.save_ban_list(&ban_list) {
+                error!("Failed to persist ban list: {}", e);
+            }
+        }
+
+        let mut peers = self.peers.write().await;
+        let matches: Vec<Uuid> = peers
+            .keys()
+            .copied()
+            .filter(|id| match &target {
+                BanTarget::Uuid(uuid) => id == uuid,
+                BanTarget::Ip(ip) => peers.get(id).map(|p| p.ip.ip() == *ip).unwrap_or(false),
+            })
+            .collect();
+
+        for id in matches {
+            if let Some(peer) = peers.get_mut(&id) {
+                peer.connected = false;
+                peer.disconnect().await;
+            }
+        }
+
+        AdminResponse::Ok
+    }
 }
 
 async fn receive_packet(reader: &mut ReadHalf<TcpStream>) -> Result<Packet> {
@@ -381,3 +749,138 @@ async fn receive_packet(reader: &mut ReadHalf<TcpStream>) -> Result<Packet> {
 
     Ok(header.make_packet(body)?)
 }
+
+/// Periodically evicts peers that `handle_connection` has marked `connected = false`
+/// (either disconnected cleanly or reaped by the idle keepalive check) from both the
+/// `peers` and `players` maps, so they stop occupying a `MAX_PLAYER` slot.
+async fn reap_stale_peers(peers: Arc<RwLock<HashMap<Uuid, Peer>>>, players: Players, metrics: Arc<Metrics>) {
+    let mut interval = tokio::time::interval(REAP_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        let stale: Vec<Uuid> = peers
+            .read()
+            .await
+            .iter()
+            .filter(|(_, peer)| !peer.connected)
+            .map(|(id, _)| *id)
+            .collect();
+
+        if stale.is_empty() {
+            continue;
+        }
+
+        let mut peers = peers.write().await;
+        for id in &stale {
+            peers.remove(id);
+            players.remove(id).await;
+
+            metrics.peers_connected.dec();
+            metrics.players_known.dec();
+        }
+        drop(peers);
+
+        debug!("Reaped {} stale peer(s)", stale.len());
+    }
+}
+
+/// Waits out `MOON_SYNC_DELAY` then resends `shines` to `id`, mirroring the
+/// original server's `ClientSyncShineBag` behavior for re-enabling moon sync
+/// after a speedrun save clears Cascade.
+async fn sync_shine_bag(peers: Arc<RwLock<HashMap<Uuid, Peer>>>, id: Uuid, shines: Vec<i32>) {
+    tokio::time::sleep(MOON_SYNC_DELAY).await;
+
+    let peers = peers.read().await;
+    if let Some(peer) = peers.get(&id) {
+        for shine in shines {
+            peer.send(Packet::new(id, Content::Shine { id: shine })).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::{TcpListener, TcpStream as TokioTcpStream};
+
+    fn test_storage() -> Arc<Storage> {
+        let dir = std::env::temp_dir().join(format!("smo-server-test-{}", Uuid::new_v4()));
+        Arc::new(Storage::open(&dir).unwrap())
+    }
+
+    // `Peer` needs a real socket half to write to, so tests spin up a loopback
+    // connection rather than faking the writer.
+    async fn connected_peer() -> Peer {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let _client = TokioTcpStream::connect(addr).await.unwrap();
+        let (server_side, peer_addr) = listener.accept().await.unwrap();
+        let (_, writer) = split(server_side);
+
+        Peer::new(peer_addr, writer)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reap_stale_peers_evicts_disconnected_entries() {
+        let peers = Arc::<RwLock<HashMap<Uuid, Peer>>>::default();
+        let players = Players::new(test_storage());
+        let metrics = Arc::new(Metrics::new());
+
+        let peer = connected_peer().await;
+        let id = peer.id;
+
+        peers.write().await.insert(id, peer);
+        players.add(Player::new(id, "tester".to_string())).await;
+        peers.write().await.get_mut(&id).unwrap().connected = false;
+
+        tokio::spawn(reap_stale_peers(peers.clone(), players.clone(), metrics.clone()));
+
+        tokio::time::advance(REAP_INTERVAL + Duration::from_millis(1)).await;
+        tokio::task::yield_now().await;
+
+        assert!(peers.read().await.get(&id).is_none());
+        assert!(players.get(&id).await.is_none());
+    }
+
+    fn test_server() -> Server {
+        let storage_path = std::env::temp_dir().join(format!("smo-server-admin-test-{}", Uuid::new_v4()));
+
+        Server::new(Settings {
+            storage_path,
+            ..Settings::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn admin_disconnect_flags_peer_and_closes_its_channel() {
+        let server = test_server();
+
+        let peer = connected_peer().await;
+        let id = peer.id;
+
+        server.peers.write().await.insert(id, peer);
+
+        let response = server.admin_disconnect(id).await;
+
+        assert!(matches!(response, AdminResponse::Ok));
+        assert!(!server.peers.read().await.get(&id).unwrap().connected);
+        assert!(!server
+            .peers
+            .read()
+            .await
+            .get(&id)
+            .unwrap()
+            .try_send(Packet::new(id, Content::Keepalive)));
+    }
+
+    #[tokio::test]
+    async fn admin_disconnect_reports_unknown_peer() {
+        let server = test_server();
+
+        let response = server.admin_disconnect(Uuid::new_v4()).await;
+
+        assert!(matches!(response, AdminResponse::Error(_)));
+    }
+}
@@ -0,0 +1,58 @@
+use std::{net::IpAddr, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BanList {
+    pub ips: Vec<IpAddr>,
+    pub ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Settings {
+    pub is_merge_enabled: bool,
+    /// Enables the server-authoritative hide-and-seek Tag game mode.
+    pub is_tag_enabled: bool,
+    pub ban_list: BanList,
+    /// Port to serve Prometheus metrics on. `None` disables the metrics listener.
+    pub metrics_port: Option<u16>,
+    /// Where player profiles, shine bags and the ban list are persisted.
+    pub storage_path: PathBuf,
+    /// Port to accept admin control connections on. `None` disables moderation.
+    pub admin_port: Option<u16>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            is_merge_enabled: false,
+            is_tag_enabled: false,
+            ban_list: BanList::default(),
+            metrics_port: None,
+            storage_path: PathBuf::from("data"),
+            admin_port: None,
+        }
+    }
+}
+
+impl Settings {
+    /// Builds settings from defaults, overriding the fields an operator needs to
+    /// flip without a recompile from their environment.
+    pub fn from_env() -> Self {
+        Self {
+            metrics_port: env_port("SMO_METRICS_PORT"),
+            admin_port: env_port("SMO_ADMIN_PORT"),
+            is_tag_enabled: env_bool("SMO_TAG_ENABLED"),
+            ..Self::default()
+        }
+    }
+}
+
+fn env_port(key: &str) -> Option<u16> {
+    std::env::var(key).ok()?.parse().ok()
+}
+
+fn env_bool(key: &str) -> bool {
+    matches!(std::env::var(key).ok().as_deref(), Some("1" | "true"))
+}
@@ -0,0 +1,101 @@
+use std::path::Path;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{players::Costume, settings::BanList};
+
+/// What we keep for a player across restarts: enough to skip the moon sync
+/// handshake and restore their last known appearance on reconnect.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PersistedPlayer {
+    pub name: String,
+    pub costume: Option<Costume>,
+    pub shines: Vec<i32>,
+}
+
+const BAN_LIST_KEY: &[u8] = b"ban_list";
+
+/// Keyed, durable storage for player profiles/shine bags and the ban list.
+/// Backed by a `sled` database so readers/writers of different keys never
+/// contend with each other.
+pub struct Storage {
+    players: sled::Tree,
+    meta: sled::Tree,
+}
+
+impl Storage {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let db = sled::open(path)?;
+        let players = db.open_tree("players")?;
+        let meta = db.open_tree("meta")?;
+
+        Ok(Self { players, meta })
+    }
+
+    // `sled`'s API is synchronous disk I/O; run it on the blocking pool so a slow
+    // read/write doesn't stall every other connection sharing this tokio worker.
+    pub async fn load_player(&self, id: &Uuid) -> Result<Option<PersistedPlayer>> {
+        let players = self.players.clone();
+        let id = *id;
+
+        tokio::task::spawn_blocking(move || match players.get(id.as_bytes())? {
+            Some(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            None => Ok(None),
+        })
+        .await?
+    }
+
+    pub async fn save_player(&self, id: &Uuid, player: &PersistedPlayer) -> Result<()> {
+        let players = self.players.clone();
+        let id = *id;
+        let player = player.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let bytes = serde_json::to_vec(&player)?;
+            players.insert(id.as_bytes(), bytes)?;
+
+            Ok(())
+        })
+        .await?
+    }
+
+    pub fn load_ban_list(&self) -> Result<BanList> {
+        match self.meta.get(BAN_LIST_KEY)? {
+            Some(bytes) => Ok(serde_json::from_slice(&bytes)?),
+            None => Ok(BanList::default()),
+        }
+    }
+
+    pub fn save_ban_list(&self, ban_list: &BanList) -> Result<()> {
+        let bytes = serde_json::to_vec(ban_list)?;
+        self.meta.insert(BAN_LIST_KEY, bytes)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn save_and_load_player_round_trips() {
+        let dir = std::env::temp_dir().join(format!("smo-storage-test-{}", Uuid::new_v4()));
+        let storage = Storage::open(&dir).unwrap();
+
+        let id = Uuid::new_v4();
+        let player = PersistedPlayer {
+            name: "Mario".to_string(),
+            costume: None,
+            shines: vec![1, 2, 3],
+        };
+
+        storage.save_player(&id, &player).await.unwrap();
+        let loaded = storage.load_player(&id).await.unwrap().unwrap();
+
+        assert_eq!(loaded.name, player.name);
+        assert_eq!(loaded.shines, player.shines);
+    }
+}
@@ -0,0 +1,117 @@
+use std::{collections::HashMap, collections::HashSet, sync::Arc, time::Duration};
+
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::{
+    packet::{Content, Packet},
+    peer::{self, Peer},
+};
+
+/// A server-broadcast countdown update.
+pub const UPDATE_TYPE_TIME: u8 = 0;
+/// A player transitioning to or from being a seeker ("it").
+pub const UPDATE_TYPE_STATE: u8 = 1;
+/// The round's timer has run out.
+pub const UPDATE_TYPE_END: u8 = 2;
+
+/// Authoritative state for the current hide-and-seek round, owned by the `Server`
+/// and mutated both by incoming `Content::Tag` packets and the round ticker.
+#[derive(Default)]
+pub struct TagState {
+    pub running: bool,
+    pub remaining_seconds: u32,
+    pub seekers: HashSet<Uuid>,
+}
+
+/// Ticks the round clock down once a second, broadcasting the remaining time to
+/// every connected peer, until it hits zero or the round is stopped.
+pub async fn run_ticker(peers: Arc<RwLock<HashMap<Uuid, Peer>>>, tag: Arc<RwLock<TagState>>) {
+    let mut interval = tokio::time::interval(Duration::from_secs(1));
+
+    loop {
+        interval.tick().await;
+
+        let mut state = tag.write().await;
+        if !state.running {
+            return;
+        }
+
+        if state.remaining_seconds == 0 {
+            state.running = false;
+            drop(state);
+
+            broadcast_time(&peers, UPDATE_TYPE_END, 0).await;
+            return;
+        }
+
+        state.remaining_seconds -= 1;
+        let remaining = state.remaining_seconds;
+        drop(state);
+
+        broadcast_time(&peers, UPDATE_TYPE_TIME, remaining).await;
+    }
+}
+
+async fn broadcast_time(peers: &Arc<RwLock<HashMap<Uuid, Peer>>>, update_type: u8, remaining_seconds: u32) {
+    let minutes = (remaining_seconds / 60) as u16;
+    let seconds = (remaining_seconds % 60) as u8;
+
+    let packet = Packet::new(
+        Uuid::nil(),
+        Content::Tag {
+            update_type,
+            is_it: false,
+            seconds,
+            minutes,
+        },
+    );
+
+    let fell_behind: Vec<Uuid> = {
+        let peers = peers.read().await;
+        peers
+            .iter()
+            .filter(|(_, p)| p.connected)
+            .filter_map(|(id, p)| {
+                if p.try_send(packet.clone()) {
+                    None
+                } else {
+                    Some(*id)
+                }
+            })
+            .collect()
+    };
+
+    peer::disconnect_slow_peers(peers, fell_behind).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn run_ticker_counts_down_and_stops_at_zero() {
+        let peers: Arc<RwLock<HashMap<Uuid, Peer>>> = Arc::default();
+        let tag = Arc::new(RwLock::new(TagState {
+            running: true,
+            remaining_seconds: 2,
+            seekers: HashSet::new(),
+        }));
+
+        run_ticker(peers, tag.clone()).await;
+
+        let state = tag.read().await;
+        assert!(!state.running);
+        assert_eq!(state.remaining_seconds, 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn run_ticker_returns_immediately_when_not_running() {
+        let peers: Arc<RwLock<HashMap<Uuid, Peer>>> = Arc::default();
+        let tag = Arc::new(RwLock::new(TagState::default()));
+
+        run_ticker(peers, tag.clone()).await;
+
+        assert!(!tag.read().await.running);
+    }
+}